@@ -0,0 +1,38 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use std::io::Write;
+use winrt::Guid;
+
+fn format(c: &mut Criterion) {
+    let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+    let (data1, data2, data3, data4) = guid.components();
+
+    c.bench_function("fmt_fast", |b| {
+        let mut buffer = [0; Guid::DISPLAY_LEN];
+        b.iter(|| black_box(&guid).fmt_fast(&mut buffer))
+    });
+
+    c.bench_function("write", |b| {
+        let mut buffer = [0u8; Guid::DISPLAY_LEN];
+        b.iter(|| {
+            write!(
+                &mut buffer[..],
+                "{:08x}-{:04x}-{:04x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+                black_box(data1),
+                data2,
+                data3,
+                data4[0],
+                data4[1],
+                data4[2],
+                data4[3],
+                data4[4],
+                data4[5],
+                data4[6],
+                data4[7]
+            )
+            .unwrap()
+        })
+    });
+}
+
+criterion_group!(benches, format);
+criterion_main!(benches);
@@ -0,0 +1,87 @@
+use crate::*;
+use once_cell::sync::OnceCell;
+
+/// A `Guid` that formats itself at most once.
+///
+/// The canonical string form is computed on first use and stored alongside the
+/// `Guid`, so repeatedly displaying the same value (for example when logging)
+/// doesn't pay for formatting each time. `CachedGuid` derefs to `Guid` so it can
+/// be used anywhere a `&Guid` is expected.
+///
+/// A `CachedGuid` can be shared between threads and, since `new` is a `const fn`,
+/// stored in a `static`.
+#[derive(Clone, Default)]
+pub struct CachedGuid {
+    guid: Guid,
+    text: OnceCell<String>,
+}
+
+impl CachedGuid {
+    /// Wraps the given `Guid` without formatting it.
+    pub const fn new(guid: Guid) -> Self {
+        Self {
+            guid,
+            text: OnceCell::new(),
+        }
+    }
+
+    /// The canonical lowercase string form of the `Guid`, formatted on first use.
+    pub fn as_str(&self) -> &str {
+        self.text.get_or_init(|| self.guid.to_string())
+    }
+}
+
+impl From<Guid> for CachedGuid {
+    fn from(guid: Guid) -> Self {
+        Self::new(guid)
+    }
+}
+
+impl std::ops::Deref for CachedGuid {
+    type Target = Guid;
+
+    fn deref(&self) -> &Guid {
+        &self.guid
+    }
+}
+
+impl std::fmt::Display for CachedGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::fmt::Debug for CachedGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.guid, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_once() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<CachedGuid>();
+
+        static STATIC: CachedGuid = CachedGuid::new(Guid::from_values(
+            0xCFF52E04,
+            0xCCA6,
+            0x4614,
+            [0xA1, 0x7E, 0x75, 0x49, 0x10, 0xC8, 0x4A, 0x99],
+        ));
+        assert!(STATIC.as_str() == "cff52e04-cca6-4614-a17e-754910c84a99");
+
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let cached = CachedGuid::new(guid.clone());
+
+        let first = cached.as_str();
+        let second = cached.as_str();
+        assert!(first == guid.to_string());
+        assert!(std::ptr::eq(first, second));
+        assert!(*cached == guid);
+        assert!(cached.to_string() == guid.to_string());
+    }
+}
@@ -0,0 +1,65 @@
+use super::ComInterface;
+use crate::{ErrorCode, Guid, GuidRef, RawPtr, Result};
+
+/// The IID of the interface `T` paired with an out parameter to receive it, mirroring the
+/// C++ `IID_PPV_ARGS` macro.
+///
+/// Taking both arguments from the same `IidPpv` ensures that the IID passed to a function
+/// such as `QueryInterface` always matches the type of the interface it writes back.
+pub struct IidPpv<'a, T: ComInterface> {
+    iid: Guid,
+    ppv: &'a mut T,
+}
+
+impl<'a, T: ComInterface> IidPpv<'a, T> {
+    /// Pairs the IID of `T` with the out parameter `ppv`.
+    pub fn new(ppv: &'a mut T) -> Self {
+        Self { iid: T::iid(), ppv }
+    }
+
+    /// The `REFIID` and `void**` arguments to pass to the function.
+    ///
+    /// Any interface already held by the out parameter is released.
+    pub fn args(&mut self) -> (GuidRef<'_>, *mut RawPtr) {
+        (GuidRef::new(&self.iid), self.ppv.set_abi() as *mut RawPtr)
+    }
+
+    /// The out parameter, populated by the function, if `code` indicates success.
+    pub fn result(self, code: ErrorCode) -> Result<&'a mut T> {
+        let ppv = self.ppv;
+        code.and_then(|| ppv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AbiTransferable, IUnknown};
+
+    #[test]
+    fn iid_ppv() {
+        static OBJECT: usize = 0;
+
+        unsafe extern "system" fn activate(iid: *const Guid, ppv: *mut RawPtr) -> ErrorCode {
+            if *iid != IUnknown::iid() {
+                return ErrorCode(0x8000_4002); // E_NOINTERFACE
+            }
+
+            *ppv = &OBJECT as *const usize as RawPtr;
+            ErrorCode(0)
+        }
+
+        let mut unknown = IUnknown::default();
+        let mut args = IidPpv::new(&mut unknown);
+        let (iid, ppv) = args.args();
+        let code = unsafe { activate(iid.get_abi(), ppv) };
+        let unknown = args.result(code).ok().unwrap();
+
+        assert!(!unknown.is_null());
+        let raw = unknown.get_abi().unwrap().as_raw() as RawPtr;
+        assert!(raw == &OBJECT as *const usize as RawPtr);
+
+        // The object isn't real so it mustn't be released.
+        std::mem::forget(std::mem::take(unknown));
+    }
+}
@@ -1,5 +1,6 @@
 //! All COM related functionality
 
+mod iid_ppv;
 mod interface;
 mod ptr;
 mod raw_ptr;
@@ -7,6 +8,7 @@ mod ref_count;
 mod try_into;
 mod unknown;
 
+pub use iid_ppv::IidPpv;
 pub use interface::ComInterface;
 pub use ptr::ComPtr;
 pub use raw_ptr::{NonNullRawComPtr, RawComPtr};
@@ -1,9 +1,10 @@
 use super::{AbiTransferable, RuntimeType};
+use std::convert::TryFrom;
 
 /// A globally unique identifier [(GUID)](https://docs.microsoft.com/en-us/dotnet/api/system.guid?view=netcore-3.1)
 /// used to uniquely identify COM and WinRT interfaces.
 #[repr(C)]
-#[derive(Clone, Default, PartialEq)]
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Guid {
     data1: u32,
     data2: u16,
@@ -12,6 +13,17 @@ pub struct Guid {
 }
 
 impl Guid {
+    /// The [RFC 4122 nil UUID](https://tools.ietf.org/html/rfc4122#section-4.1.7), which
+    /// has all 128 bits set to zero. This is the same value as `Guid::zeroed()`.
+    pub const NIL: Guid = Guid::zeroed();
+
+    /// The length of the dashed form of a `Guid`, as produced by `Display`.
+    pub const DISPLAY_LEN: usize = 36;
+
+    /// The length of the dashed form of a `Guid` wrapped in braces, as produced by
+    /// `to_registry_string`.
+    pub const BRACED_LEN: usize = Self::DISPLAY_LEN + 2;
+
     /// Creates a `Guid` represented by the all-zero byte-pattern.
     pub const fn zeroed() -> Guid {
         Guid {
@@ -32,24 +44,548 @@ impl Guid {
         }
     }
 
+    /// Returns `true` if this is the nil `Guid`, with all bits set to zero.
+    pub const fn is_nil(&self) -> bool {
+        self.to_u128_be() == 0
+    }
+
+    /// Returns the four fields of the `Guid` in the same shape accepted by `from_values`.
+    pub const fn components(&self) -> (u32, u16, u16, [u8; 8]) {
+        (self.data1, self.data2, self.data3, self.data4)
+    }
+
+    /// Returns a copy of the `Guid` with `data1` replaced.
+    pub const fn with_data1(&self, data1: u32) -> Guid {
+        Self::from_values(data1, self.data2, self.data3, self.data4)
+    }
+
+    /// Returns a copy of the `Guid` with `data2` replaced.
+    pub const fn with_data2(&self, data2: u16) -> Guid {
+        Self::from_values(self.data1, data2, self.data3, self.data4)
+    }
+
+    /// Returns a copy of the `Guid` with `data3` replaced.
+    pub const fn with_data3(&self, data3: u16) -> Guid {
+        Self::from_values(self.data1, self.data2, data3, self.data4)
+    }
+
+    /// Returns a copy of the `Guid` with `data4` replaced.
+    pub const fn with_data4(&self, data4: [u8; 8]) -> Guid {
+        Self::from_values(self.data1, self.data2, self.data3, data4)
+    }
+
+    /// Returns a copy of the `Guid` with everything but `data1` and the version and
+    /// variant bits zeroed, for logging the shape of a `Guid` without the full value.
+    ///
+    /// This is lossy and irreversible: the original `Guid` can't be recovered.
+    pub const fn anonymized(&self) -> Guid {
+        // The variant is the leading `0`, `10`, `110` or `111` of `data4[0]`, so keep one,
+        // two or three bits with the rest being part of the clock sequence or random.
+        let first = self.data4[0] >> 7;
+        let second = (self.data4[0] >> 6) & 1;
+        let variant = 0x80 | (first << 6) | ((first & second) << 5);
+
+        Guid {
+            data1: self.data1,
+            data2: 0,
+            data3: self.data3 & 0xf000,
+            data4: [self.data4[0] & variant, 0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+
+    /// Creates a `Guid` from its 16 byte in-memory representation, where the first
+    /// three fields are little-endian.
+    pub const fn from_bytes_le(bytes: [u8; 16]) -> Guid {
+        // The integer `from_le_bytes` functions are only const as of Rust 1.44.
+        Guid {
+            data1: bytes[0] as u32
+                | (bytes[1] as u32) << 8
+                | (bytes[2] as u32) << 16
+                | (bytes[3] as u32) << 24,
+            data2: bytes[4] as u16 | (bytes[5] as u16) << 8,
+            data3: bytes[6] as u16 | (bytes[7] as u16) << 8,
+            data4: [
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ],
+        }
+    }
+
+    /// Returns the 16 byte in-memory representation of the `Guid`, where the first
+    /// three fields are little-endian.
+    pub const fn to_bytes_le(&self) -> [u8; 16] {
+        // The integer `to_le_bytes` functions are only const as of Rust 1.44.
+        let a = self.data1;
+        let b = self.data2;
+        let c = self.data3;
+        let d = self.data4;
+
+        [
+            a as u8,
+            (a >> 8) as u8,
+            (a >> 16) as u8,
+            (a >> 24) as u8,
+            b as u8,
+            (b >> 8) as u8,
+            c as u8,
+            (c >> 8) as u8,
+            d[0],
+            d[1],
+            d[2],
+            d[3],
+            d[4],
+            d[5],
+            d[6],
+            d[7],
+        ]
+    }
+
+    /// Creates a `Guid` from its 16 byte RFC 4122 representation, where every field is
+    /// big-endian (network order), as opposed to the in-memory order of `from_bytes_le`.
+    pub const fn from_rfc_bytes(bytes: [u8; 16]) -> Guid {
+        Self::from_values(
+            (bytes[0] as u32) << 24
+                | (bytes[1] as u32) << 16
+                | (bytes[2] as u32) << 8
+                | bytes[3] as u32,
+            (bytes[4] as u16) << 8 | bytes[5] as u16,
+            (bytes[6] as u16) << 8 | bytes[7] as u16,
+            [
+                bytes[8], bytes[9], bytes[10], bytes[11], bytes[12], bytes[13], bytes[14],
+                bytes[15],
+            ],
+        )
+    }
+
+    /// Returns the 16 byte RFC 4122 representation of the `Guid`, where every field is
+    /// big-endian (network order).
+    pub const fn to_rfc_bytes(&self) -> [u8; 16] {
+        let a = self.data1;
+        let b = self.data2;
+        let c = self.data3;
+        let d = self.data4;
+
+        [
+            (a >> 24) as u8,
+            (a >> 16) as u8,
+            (a >> 8) as u8,
+            a as u8,
+            (b >> 8) as u8,
+            b as u8,
+            (c >> 8) as u8,
+            c as u8,
+            d[0],
+            d[1],
+            d[2],
+            d[3],
+            d[4],
+            d[5],
+            d[6],
+            d[7],
+        ]
+    }
+
+    /// Creates a `Guid` from the 16 byte binary UUID representation used by databases
+    /// such as PostgreSQL. This is the big-endian order of `from_rfc_bytes`, and the
+    /// same order as the `uuid` crate's `Uuid::from_bytes`.
+    pub const fn from_sql_bytes(bytes: [u8; 16]) -> Guid {
+        Self::from_rfc_bytes(bytes)
+    }
+
+    /// Returns the 16 byte binary UUID representation used by databases such as
+    /// PostgreSQL. This is the big-endian order of `to_rfc_bytes`, and the same order as
+    /// the `uuid` crate's `Uuid::as_bytes`.
+    pub const fn to_sql_bytes(&self) -> [u8; 16] {
+        self.to_rfc_bytes()
+    }
+
+    /// Creates a `Guid` from a `u128` whose big-endian bytes are in textual order, so that
+    /// `0xCFF52E04_CCA6_4614_A17E_754910C84A99` is `CFF52E04-CCA6-4614-A17E-754910C84A99`.
+    pub const fn from_u128_be(value: u128) -> Guid {
+        Self::from_values(
+            (value >> 96) as u32,
+            (value >> 80) as u16,
+            (value >> 64) as u16,
+            [
+                (value >> 56) as u8,
+                (value >> 48) as u8,
+                (value >> 40) as u8,
+                (value >> 32) as u8,
+                (value >> 24) as u8,
+                (value >> 16) as u8,
+                (value >> 8) as u8,
+                value as u8,
+            ],
+        )
+    }
+
+    /// Returns the `u128` whose big-endian bytes are the `Guid` in textual order.
+    pub const fn to_u128_be(&self) -> u128 {
+        let d = self.data4;
+
+        (self.data1 as u128) << 96
+            | (self.data2 as u128) << 80
+            | (self.data3 as u128) << 64
+            | (d[0] as u128) << 56
+            | (d[1] as u128) << 48
+            | (d[2] as u128) << 40
+            | (d[3] as u128) << 32
+            | (d[4] as u128) << 24
+            | (d[5] as u128) << 16
+            | (d[6] as u128) << 8
+            | d[7] as u128
+    }
+
+    /// A key that orders `Guid`s the same way as their `Ord` implementation, which is
+    /// cheaper to compare when sorting records by `Guid` repeatedly.
+    pub const fn sort_key(&self) -> u128 {
+        self.to_u128_be()
+    }
+
+    /// Creates a `Guid` from a `u128` whose little-endian bytes are the in-memory
+    /// representation of the `Guid`, as with `from_bytes_le`.
+    pub const fn from_u128_le(value: u128) -> Guid {
+        Self::from_values(
+            value as u32,
+            (value >> 32) as u16,
+            (value >> 48) as u16,
+            [
+                (value >> 64) as u8,
+                (value >> 72) as u8,
+                (value >> 80) as u8,
+                (value >> 88) as u8,
+                (value >> 96) as u8,
+                (value >> 104) as u8,
+                (value >> 112) as u8,
+                (value >> 120) as u8,
+            ],
+        )
+    }
+
+    /// Returns the `u128` whose little-endian bytes are the in-memory representation of
+    /// the `Guid`, as with `to_bytes_le`.
+    pub const fn to_u128_le(&self) -> u128 {
+        let d = self.data4;
+
+        self.data1 as u128
+            | (self.data2 as u128) << 32
+            | (self.data3 as u128) << 48
+            | (d[0] as u128) << 64
+            | (d[1] as u128) << 72
+            | (d[2] as u128) << 80
+            | (d[3] as u128) << 88
+            | (d[4] as u128) << 96
+            | (d[5] as u128) << 104
+            | (d[6] as u128) << 112
+            | (d[7] as u128) << 120
+    }
+
+    /// Writes the `Guid` as 16 bytes using the little-endian layout of `to_bytes_le`.
+    pub fn write_le<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.to_bytes_le())
+    }
+
+    /// Reads a `Guid` written by `write_le`.
+    pub fn read_le<R: std::io::Read>(reader: &mut R) -> std::io::Result<Guid> {
+        let mut bytes = [0; 16];
+        reader.read_exact(&mut bytes)?;
+        Ok(Self::from_bytes_le(bytes))
+    }
+
+    /// Combines two `Guid`s by XOR-ing their bytes together.
+    ///
+    /// This is a cheap, deterministic way to derive a composite key from a pair of
+    /// `Guid`s. Note the result is not a valid RFC 4122 UUID as the version and
+    /// variant bits are mixed along with everything else.
+    pub const fn xor(&self, other: &Guid) -> Guid {
+        let a = self.data4;
+        let b = other.data4;
+
+        Guid {
+            data1: self.data1 ^ other.data1,
+            data2: self.data2 ^ other.data2,
+            data3: self.data3 ^ other.data3,
+            data4: [
+                a[0] ^ b[0],
+                a[1] ^ b[1],
+                a[2] ^ b[2],
+                a[3] ^ b[3],
+                a[4] ^ b[4],
+                a[5] ^ b[5],
+                a[6] ^ b[6],
+                a[7] ^ b[7],
+            ],
+        }
+    }
+
+    /// Maps the `Guid` to one of `n` buckets by folding its high and low 64 bits together,
+    /// for example to pick a lock stripe. Returns `0` if `n` is `0`.
+    pub fn bucket(&self, n: usize) -> usize {
+        if n == 0 {
+            return 0;
+        }
+
+        let bits = self.to_u128_be();
+        let folded = (bits >> 64) as u64 ^ bits as u64;
+        (folded % n as u64) as usize
+    }
+
+    /// Returns a new `Guid` whose `to_bytes_le` representation is the byte-wise AND of
+    /// this `Guid`'s and `mask`, for example to reduce a `Guid` to a shard key.
+    ///
+    /// Mask index `i` applies to `to_bytes_le()[i]`, not to the `i`th byte of the text.
+    /// The first three fields are little-endian, so index 0 is the lowest byte of `data1`.
+    /// To keep the first byte of the text, `CF` in `CFF52E04-...`, set index 3.
+    ///
+    /// Like `xor`, the result is generally not a valid RFC 4122 UUID.
+    pub const fn masked(&self, mask: &[u8; 16]) -> Guid {
+        let b = self.to_bytes_le();
+        let m = mask;
+
+        Self::from_bytes_le([
+            b[0] & m[0],
+            b[1] & m[1],
+            b[2] & m[2],
+            b[3] & m[3],
+            b[4] & m[4],
+            b[5] & m[5],
+            b[6] & m[6],
+            b[7] & m[7],
+            b[8] & m[8],
+            b[9] & m[9],
+            b[10] & m[10],
+            b[11] & m[11],
+            b[12] & m[12],
+            b[13] & m[13],
+            b[14] & m[14],
+            b[15] & m[15],
+        ])
+    }
+
+    /// Compares two `Guid`s without exiting early on the first difference, so the time
+    /// taken doesn't reveal how much of `other` matches.
+    ///
+    /// This is only meant for security-sensitive comparisons, such as checking a `Guid`
+    /// that acts as a capability token. Use `==` everywhere else.
+    pub fn ct_eq(&self, other: &Guid) -> bool {
+        let difference = self
+            .to_bytes_le()
+            .iter()
+            .zip(other.to_bytes_le().iter())
+            .fold(0u8, |difference, (a, b)| difference | (a ^ b));
+
+        difference == 0
+    }
+
     /// Creates a `Guid` for a "generic" WinRT type.
     ///
+    /// The type's signature is hashed as UTF-8.
+    ///
     /// Note this needs to be a const function as soon as [Rust supports it](https://github.com/microsoft/winrt-rs/issues/136).
     pub fn from_signature<T: RuntimeType>() -> Guid {
-        let mut data = vec![
-            0x11, 0xf4, 0x7a, 0xd5, 0x7b, 0x73, 0x42, 0xc0, 0xab, 0xae, 0x87, 0x8b, 0x1e, 0x16,
-            0xad, 0xee,
+        Self::from_signature_bytes(T::signature().as_bytes())
+    }
+
+    /// Returns the `Guid` identifying any WinRT type `T` from its signature.
+    ///
+    /// The signature of a non-generic interface or delegate already holds its `Guid`,
+    /// which is returned as is, and a runtime class is identified by the `Guid` of its
+    /// default interface, as with C++/WinRT's `guid_of`. Everything else, including
+    /// parameterized types, is hashed as with `from_signature`.
+    pub fn of<T: RuntimeType>() -> Guid {
+        guid_of_signature(&T::signature())
+    }
+
+    /// Creates a `Guid` for a "generic" WinRT type from a UTF-16 encoded signature.
+    ///
+    /// The signature is hashed as UTF-8, so the UTF-16 code units are transcoded first
+    /// and an error is returned if they aren't valid UTF-16.
+    pub fn from_signature_utf16(units: &[u16]) -> Result<Guid, std::string::FromUtf16Error> {
+        let signature = String::from_utf16(units)?;
+        Ok(Self::from_signature_bytes(signature.as_bytes()))
+    }
+
+    /// Creates a `Guid` for a "generic" WinRT type from its UTF-8 encoded signature after
+    /// checking that the signature is well-formed.
+    ///
+    /// `from_signature` will happily hash a malformed signature into some `Guid`, so this
+    /// is useful for catching mistakes in hand-written signatures. The brackets and braces
+    /// must balance and every `(` must follow a known signature keyword such as
+    /// `pinterface` or `struct`.
+    pub fn from_signature_validated(signature: &[u8]) -> Result<Guid, MalformedSignature> {
+        const KEYWORDS: [&[u8]; 6] = [
+            b"pinterface",
+            b"cinterface",
+            b"delegate",
+            b"struct",
+            b"enum",
+            b"rc",
         ];
-        data.extend_from_slice(T::signature().as_bytes());
 
-        let mut hash = sha1::Sha1::new();
-        hash.update(&data);
-        let bytes = hash.digest().bytes();
+        let mut open = Vec::new();
+        let mut keyword = 0;
+
+        for (position, byte) in signature.iter().enumerate() {
+            match byte {
+                b'(' => {
+                    if !KEYWORDS.contains(&&signature[keyword..position]) {
+                        return Err(MalformedSignature::UnknownKeyword { position: keyword });
+                    }
+
+                    open.push((b')', position));
+                }
+                b'{' => open.push((b'}', position)),
+                b')' | b'}' => match open.pop() {
+                    Some((close, _)) if close == *byte => {}
+                    _ => return Err(MalformedSignature::UnexpectedClose { position }),
+                },
+                _ => {}
+            }
+
+            if !byte.is_ascii_alphanumeric() {
+                keyword = position + 1;
+            }
+        }
+
+        if let Some((_, position)) = open.pop() {
+            return Err(MalformedSignature::Unclosed { position });
+        }
+
+        Ok(Self::from_signature_bytes(signature))
+    }
+
+    /// Creates the `Guid` of a parameterized interface from the `piid` of the generic
+    /// interface and the signatures of its type arguments, after checking that `piid`
+    /// looks like a genuine interface ID.
+    ///
+    /// Each of the `args` is the signature of a type argument, as returned by
+    /// `RuntimeType::signature`, for example `i4` for `i32`.
+    ///
+    /// This catches a nil or random value being passed in place of the intended PIID.
+    /// The PIIDs of the generic interfaces in `Windows.Foundation` use several different
+    /// UUID versions, such as version 1 for `IReference<T>` and version 4 for `IVector<T>`,
+    /// so any RFC 4122 version is accepted but the variant must be RFC 4122.
+    pub fn parameterized_checked(piid: &Guid, args: &[&str]) -> Result<Guid, BadPiid> {
+        if piid.is_nil() {
+            return Err(BadPiid::Nil);
+        }
+
+        if piid.data4[0] & 0xc0 != 0x80 {
+            return Err(BadPiid::InvalidVariant);
+        }
+
+        let version = piid.data3 >> 12;
+
+        if !(1..=5).contains(&version) {
+            return Err(BadPiid::InvalidVersion(version as u8));
+        }
+
+        Ok(Self::from_signature_bytes(
+            parameterized_signature(piid, args).as_bytes(),
+        ))
+    }
+
+    /// Returns the exact signature that `parameterized_checked` hashes for `piid` and
+    /// `args`, so that it can be compared against a reference when an IID is wrong.
+    pub fn explain_parameterized(piid: &Guid, args: &[&str]) -> String {
+        parameterized_signature(piid, args)
+    }
+
+    /// Hashes the UTF-8 encoded `signature` into a version 5 `Guid`.
+    fn from_signature_bytes(signature: &[u8]) -> Guid {
+        let mut hasher = SignatureHasher::new();
+        hasher.push(signature);
+        hasher.finalize()
+    }
+
+    /// Creates a time-based (version 1) `Guid` for the given `node`.
+    ///
+    /// The timestamp is the current system time in 100-nanosecond intervals since the
+    /// start of the Gregorian calendar (the UUID epoch) and the clock sequence is random.
+    #[cfg(feature = "rand")]
+    pub fn new_v1(node: [u8; 6]) -> Guid {
+        // The number of 100-nanosecond intervals between 1582-10-15 and 1970-01-01.
+        const UUID_EPOCH_OFFSET: u64 = 0x01b2_1dd2_1381_4000;
+
+        let since_unix_epoch = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default();
+        let timestamp = (since_unix_epoch.as_nanos() / 100) as u64 + UUID_EPOCH_OFFSET;
 
+        let mut bytes = [0; 16];
+        bytes[..4].copy_from_slice(&(timestamp as u32).to_be_bytes());
+        bytes[4..6].copy_from_slice(&((timestamp >> 32) as u16).to_be_bytes());
+        bytes[6..8].copy_from_slice(&((timestamp >> 48) as u16).to_be_bytes());
+        bytes[8..10].copy_from_slice(&rand::random::<u16>().to_be_bytes());
+        bytes[10..].copy_from_slice(&node);
+
+        Self::from_versioned_bytes(&bytes, 1)
+    }
+
+    /// Creates a name-based (version 5) `Guid` by hashing the `namespace` and `name` with
+    /// SHA-1, as described in [RFC 4122](https://tools.ietf.org/html/rfc4122#section-4.3).
+    pub fn from_name(namespace: &Guid, name: &[u8]) -> Guid {
+        let mut hasher = SignatureHasher::with_namespace(namespace);
+        hasher.push(name);
+        hasher.finalize()
+    }
+
+    /// Parses `input` as a `Guid`, bare or wrapped in braces, or if that fails derives
+    /// a `Guid` from it with `from_name`.
+    ///
+    /// This suits configuration values that may be either a literal `Guid` or a name.
+    pub fn parse_or_derive(input: &str, namespace: &Guid) -> Guid {
+        input
+            .parse()
+            .unwrap_or_else(|_| Self::from_name(namespace, input.as_bytes()))
+    }
+
+    /// Creates a name-based (version 8) `Guid` by hashing the `namespace` and `name` with
+    /// SHA-256, as described in [RFC 9562](https://www.rfc-editor.org/rfc/rfc9562#appendix-B.2).
+    ///
+    /// The digest is truncated to its first 16 bytes.
+    #[cfg(feature = "sha2")]
+    pub fn from_name_sha256(namespace: &Guid, name: &[u8]) -> Guid {
+        use sha2::Digest;
+
+        let mut hash = sha2::Sha256::new();
+        hash.update(namespace.to_u128_be().to_be_bytes());
+        hash.update(name);
+        Self::from_versioned_bytes(&hash.finalize(), 8)
+    }
+
+    /// Writes the `Guid` into `out` in the canonical lowercase dashed form, exactly as
+    /// `Display` does, using a lookup table instead of the `std::fmt` machinery.
+    pub fn fmt_fast(&self, out: &mut [u8; Self::DISPLAY_LEN]) {
+        const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let bits = self.to_u128_be();
+        let mut shift = 128;
+
+        for (index, byte) in out.iter_mut().enumerate() {
+            if is_separator(index) {
+                *byte = b'-';
+            } else {
+                shift -= 4;
+                *byte = HEX_DIGITS[((bits >> shift) & 0xf) as usize];
+            }
+        }
+    }
+
+    /// Formats the `Guid` into a stack buffer in the lowercase 36 character dashed form.
+    fn to_ascii(&self) -> [u8; Self::DISPLAY_LEN] {
+        let mut buffer = [0; Self::DISPLAY_LEN];
+        self.fmt_fast(&mut buffer);
+        buffer
+    }
+
+    /// Creates a `Guid` from the first 16 of `bytes` in textual (big-endian) order,
+    /// stamping the given `version` and the RFC 4122 variant bits.
+    fn from_versioned_bytes(bytes: &[u8], version: u16) -> Guid {
         let first = u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
         let second = u16::from_be_bytes([bytes[4], bytes[5]]);
         let mut third = u16::from_be_bytes([bytes[6], bytes[7]]);
-        third = (third & 0x0fff) | (5 << 12);
+        third = (third & 0x0fff) | (version << 12);
         let fourth = (bytes[8] & 0x3f) | 0x80;
 
         Self::from_values(
@@ -61,8 +597,377 @@ impl Guid {
             ],
         )
     }
+
+    /// Validates a `Guid` intended to be registered as a COM class ID.
+    ///
+    /// The nil (all zeros) and max (all ones) `Guid`s are reserved and almost always
+    /// indicate a bug when used as a real CLSID, so both are rejected.
+    pub fn new_clsid(value: Guid) -> Result<Guid, ReservedGuid> {
+        match value.to_u128_be() {
+            0 => Err(ReservedGuid::Nil),
+            std::u128::MAX => Err(ReservedGuid::Max),
+            _ => Ok(value),
+        }
+    }
+
+    /// Returns `true` if the `Guid` is equal to any of the `candidates`.
+    pub fn matches_any(&self, candidates: &[Guid]) -> bool {
+        self.position_in(candidates).is_some()
+    }
+
+    /// Returns the index of the first of the `candidates` equal to the `Guid`, if any.
+    ///
+    /// This is useful when implementing `QueryInterface` to find which of several
+    /// supported interfaces was requested.
+    pub fn position_in(&self, candidates: &[Guid]) -> Option<usize> {
+        candidates.iter().position(|candidate| candidate == self)
+    }
+
+    /// Returns `true` if `value` spells this `Guid` in any common form, ignoring case,
+    /// dashes, and surrounding braces. Malformed strings compare as not equal.
+    pub fn loosely_equals(&self, value: &str) -> bool {
+        let bytes = strip_braces(value).unwrap_or(value).as_bytes();
+
+        let mut bits = 0u128;
+        let mut digits = 0;
+
+        for byte in bytes.iter().filter(|byte| **byte != b'-') {
+            match hex_digit(*byte) {
+                Some(digit) if digits < 32 => {
+                    bits = (bits << 4) | u128::from(digit);
+                    digits += 1;
+                }
+                _ => return false,
+            }
+        }
+
+        digits == 32 && bits == self.to_u128_be()
+    }
+
+    /// Formats the `Guid` in the same canonical dashed form as `Display` into a fixed
+    /// capacity string, for callers that can't allocate.
+    #[cfg(feature = "heapless")]
+    pub fn to_heapless(&self) -> heapless::String<{ Self::DISPLAY_LEN }> {
+        let ascii = self.to_ascii();
+        let mut string = heapless::String::new();
+
+        for byte in ascii.iter() {
+            // The buffer holds exactly `DISPLAY_LEN` ASCII characters so this can't fail.
+            string.push(*byte as char).unwrap();
+        }
+
+        string
+    }
+
+    /// Returns `true` if `buffer` holds this `Guid` in the 36 character dashed form,
+    /// ignoring case, without parsing the buffer or allocating.
+    pub fn eq_ascii(&self, buffer: &[u8; Self::DISPLAY_LEN]) -> bool {
+        self.to_ascii().eq_ignore_ascii_case(buffer)
+    }
+
+    /// Formats the `Guid` as 32 lowercase hex digits without dashes or braces.
+    ///
+    /// This form contains no characters that need escaping, which makes it suitable
+    /// for passing through environment variables.
+    pub fn to_env_safe(&self) -> String {
+        format!("{:032x}", self.to_u128_be())
+    }
+
+    /// Parses a `Guid` from the 32 hex digit form produced by `to_env_safe`.
+    pub fn from_env_safe(value: &str) -> Result<Guid, GuidParseError> {
+        if value.len() != 32 {
+            return Err(GuidParseError::InvalidLength(value.len()));
+        }
+
+        let mut bits = 0u128;
+
+        for (position, byte) in value.bytes().enumerate() {
+            let digit = hex_digit(byte).ok_or(GuidParseError::InvalidHexDigit { position })?;
+            bits = (bits << 4) | u128::from(digit);
+        }
+
+        Ok(Self::from_u128_be(bits))
+    }
+
+    /// Formats the `Guid` in the same canonical dashed form as `Display` but with
+    /// uppercase hex digits, as expected by many Windows APIs.
+    pub fn to_string_upper(&self) -> String {
+        self.to_string().to_ascii_uppercase()
+    }
+
+    /// Formats the `Guid` the way the Windows registry stores it: uppercase and
+    /// wrapped in braces, for example `{CFF52E04-CCA6-4614-A17E-754910C84A99}`.
+    pub fn to_registry_string(&self) -> String {
+        format!("{{{}}}", self.to_string_upper())
+    }
+
+    /// Parses a `Guid` from the brace-wrapped form produced by `to_registry_string`.
+    ///
+    /// The hex digits may be upper or lowercase.
+    pub fn from_registry_string(value: &str) -> Result<Guid, GuidParseError> {
+        let bytes = value.as_bytes();
+
+        if bytes.len() != Self::BRACED_LEN {
+            return Err(GuidParseError::InvalidLength(bytes.len()));
+        }
+
+        if bytes[0] != b'{' {
+            return Err(GuidParseError::ExpectedChar {
+                expected: '{',
+                position: 0,
+            });
+        }
+
+        let last = Self::BRACED_LEN - 1;
+
+        if bytes[last] != b'}' {
+            return Err(GuidParseError::ExpectedChar {
+                expected: '}',
+                position: last,
+            });
+        }
+
+        parse(&bytes[1..last], 1)
+    }
+
+    /// Parses a `Guid` from a registry key name such as `CLSID\{CFF52E04-...}`,
+    /// stripping an optional `CLSID\`, `Interface\` or `AppID\` prefix and the braces.
+    ///
+    /// The prefix is matched case-insensitively like the registry itself. Error positions
+    /// and lengths refer to the original key.
+    pub fn from_registry_key(key: &str) -> Result<Guid, GuidParseError> {
+        const PREFIXES: [&str; 3] = ["CLSID\\", "Interface\\", "AppID\\"];
+
+        let offset = PREFIXES
+            .iter()
+            .find(|prefix| match key.get(..prefix.len()) {
+                Some(head) => head.eq_ignore_ascii_case(prefix),
+                None => false,
+            })
+            .map_or(0, |prefix| prefix.len());
+
+        let rest = &key[offset..];
+
+        let result = match strip_braces(rest) {
+            Some(inner) => parse(inner.as_bytes(), offset + 1),
+            None => parse(rest.as_bytes(), offset),
+        };
+
+        result.map_err(|error| match error {
+            GuidParseError::InvalidLength(_) => GuidParseError::InvalidLength(key.len()),
+            error => error,
+        })
+    }
+
+    /// Formats the `Guid` the way OLE automation and scripting hosts expect it in a
+    /// `BSTR`. This is the same uppercase brace-wrapped form as `to_registry_string`.
+    pub fn to_ole_string(&self) -> String {
+        self.to_registry_string()
+    }
+
+    /// Parses a `Guid` from the brace-wrapped form produced by `to_ole_string`.
+    pub fn from_ole_string(value: &str) -> Result<Guid, GuidParseError> {
+        Self::from_registry_string(value)
+    }
+
+    /// Parses a `Guid` from the 36 character dashed form encoded as UTF-16, such as the
+    /// contents of an `HString`, without transcoding to a `String` first.
+    pub fn from_utf16(units: &[u16]) -> Result<Guid, GuidParseError> {
+        parse(units, 0)
+    }
+
+    /// Parses each of the `inputs` as a `Guid`, bare or wrapped in braces and in either
+    /// case, returning the parsed `Guid`s in order along with the indices of the inputs
+    /// that failed to parse.
+    pub fn canonicalize_all(inputs: &[&str]) -> (Vec<Guid>, Vec<usize>) {
+        let mut guids = Vec::with_capacity(inputs.len());
+        let mut failed = Vec::new();
+
+        for (index, input) in inputs.iter().enumerate() {
+            match input.parse() {
+                Ok(guid) => guids.push(guid),
+                Err(_) => failed.push(index),
+            }
+        }
+
+        (guids, failed)
+    }
+
+    /// Returns `true` if `value` is a well-formed `Guid` string, either bare or wrapped
+    /// in braces, without constructing the `Guid`.
+    pub fn is_valid_str(value: &str) -> bool {
+        let bytes = strip_braces(value).unwrap_or(value).as_bytes();
+
+        bytes.len() == Self::DISPLAY_LEN
+            && bytes.iter().enumerate().all(|(index, byte)| {
+                if is_separator(index) {
+                    *byte == b'-'
+                } else {
+                    hex_digit(*byte).is_some()
+                }
+            })
+    }
 }
 
+/// The namespace WinRT uses when hashing the signatures of parameterized types.
+const PINTERFACE_NAMESPACE: Guid = Guid::from_values(
+    0x11f4_7ad5,
+    0x7b73,
+    0x42c0,
+    [0xab, 0xae, 0x87, 0x8b, 0x1e, 0x16, 0xad, 0xee],
+);
+
+/// Builds a version 5 `Guid` by streaming signature bytes through SHA-1.
+///
+/// This produces the same `Guid` as `Guid::from_signature` but doesn't require the
+/// whole signature to be assembled up front, which is useful for large signatures
+/// built from many fragments.
+pub struct SignatureHasher(sha1::Sha1);
+
+impl SignatureHasher {
+    /// Creates a hasher for the namespace WinRT uses for parameterized types.
+    pub fn new() -> Self {
+        Self::with_namespace(&PINTERFACE_NAMESPACE)
+    }
+
+    /// Creates a hasher for the given `namespace`.
+    ///
+    /// The namespace is hashed before any signature bytes, so it must be provided up front.
+    pub fn with_namespace(namespace: &Guid) -> Self {
+        let mut hash = sha1::Sha1::new();
+        hash.update(&namespace.to_u128_be().to_be_bytes());
+        Self(hash)
+    }
+
+    /// Appends the next UTF-8 encoded fragment of the signature.
+    pub fn push(&mut self, bytes: &[u8]) {
+        self.0.update(bytes);
+    }
+
+    /// Returns the `Guid` for all of the pushed signature bytes.
+    pub fn finalize(self) -> Guid {
+        Guid::from_versioned_bytes(&self.0.digest().bytes(), 5)
+    }
+}
+
+impl Default for SignatureHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The error returned when a string isn't a well-formed `Guid`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum GuidParseError {
+    /// The string doesn't have the expected length.
+    InvalidLength(usize),
+    /// A hex digit was expected at the given byte position.
+    InvalidHexDigit { position: usize },
+    /// The `expected` separator or brace was missing at the given byte position.
+    ExpectedChar { expected: char, position: usize },
+}
+
+impl std::fmt::Display for GuidParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLength(len) => write!(f, "Invalid GUID string: unexpected length {}", len),
+            Self::InvalidHexDigit { position } => write!(
+                f,
+                "Invalid GUID string: expected hex digit at position {}",
+                position
+            ),
+            Self::ExpectedChar { expected, position } => write!(
+                f,
+                "Invalid GUID string: expected '{}' at position {}",
+                expected, position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for GuidParseError {}
+
+/// The error returned by `Guid::from_signature_validated` for a malformed signature.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum MalformedSignature {
+    /// The `)` or `}` at the given byte position doesn't close a matching `(` or `{`.
+    UnexpectedClose { position: usize },
+    /// The `(` or `{` at the given byte position is never closed.
+    Unclosed { position: usize },
+    /// The word starting at the given byte position and followed by a `(` isn't a known
+    /// signature keyword.
+    UnknownKeyword { position: usize },
+}
+
+impl std::fmt::Display for MalformedSignature {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnexpectedClose { position } => write!(
+                f,
+                "Malformed signature: unexpected closing bracket at position {}",
+                position
+            ),
+            Self::Unclosed { position } => write!(
+                f,
+                "Malformed signature: bracket at position {} is never closed",
+                position
+            ),
+            Self::UnknownKeyword { position } => write!(
+                f,
+                "Malformed signature: unknown keyword at position {}",
+                position
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MalformedSignature {}
+
+/// The error returned by `Guid::parameterized_checked` for an implausible PIID.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum BadPiid {
+    /// The PIID is the nil `Guid`.
+    Nil,
+    /// The PIID doesn't have the RFC 4122 variant bits.
+    InvalidVariant,
+    /// The PIID doesn't have one of the RFC 4122 versions 1 through 5.
+    InvalidVersion(u8),
+}
+
+impl std::fmt::Display for BadPiid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nil => write!(f, "The PIID is the nil GUID"),
+            Self::InvalidVariant => write!(f, "The PIID doesn't have the RFC 4122 variant"),
+            Self::InvalidVersion(version) => {
+                write!(f, "The PIID has the unknown UUID version {}", version)
+            }
+        }
+    }
+}
+
+impl std::error::Error for BadPiid {}
+
+/// The error returned by `Guid::new_clsid` for a reserved `Guid`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum ReservedGuid {
+    /// The all-zero nil `Guid`.
+    Nil,
+    /// The all-ones max `Guid`.
+    Max,
+}
+
+impl std::fmt::Display for ReservedGuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Nil => write!(f, "The nil GUID is not a valid CLSID"),
+            Self::Max => write!(f, "The max GUID is not a valid CLSID"),
+        }
+    }
+}
+
+impl std::error::Error for ReservedGuid {}
+
 unsafe impl AbiTransferable for Guid {
     type Abi = Self;
 
@@ -100,62 +1005,857 @@ impl std::fmt::Debug for Guid {
     }
 }
 
+impl std::fmt::Display for Guid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let buffer = self.to_ascii();
+        f.pad(std::str::from_utf8(&buffer).unwrap())
+    }
+}
+
+impl std::str::FromStr for Guid {
+    type Err = GuidParseError;
+
+    /// Parses a `Guid` from the 36 character dashed form, optionally wrapped in braces.
+    ///
+    /// Error positions and lengths refer to the original string, including any braces.
+    fn from_str(value: &str) -> Result<Guid, GuidParseError> {
+        let result = match strip_braces(value) {
+            Some(inner) => parse(inner.as_bytes(), 1),
+            None => parse(value.as_bytes(), 0),
+        };
+
+        result.map_err(|error| match error {
+            GuidParseError::InvalidLength(_) => GuidParseError::InvalidLength(value.len()),
+            error => error,
+        })
+    }
+}
+
+impl TryFrom<String> for Guid {
+    type Error = GuidParseError;
+
+    fn try_from(value: String) -> Result<Guid, GuidParseError> {
+        value.parse()
+    }
+}
+
+impl From<Guid> for String {
+    fn from(value: Guid) -> String {
+        value.to_string()
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for Guid {
+    /// Consumes 16 bytes of the input as the `to_bytes_le` representation of a `Guid`.
+    fn arbitrary(input: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(Self::from_bytes_le(input.arbitrary()?))
+    }
+
+    fn size_hint(_depth: usize) -> (usize, Option<usize>) {
+        (16, Some(16))
+    }
+}
+
 impl From<&str> for Guid {
     fn from(value: &str) -> Guid {
-        assert!(value.len() == 36, "Invalid GUID string");
-        let mut bytes = value.bytes();
+        parse(value.as_bytes(), 0).unwrap_or_else(|error| {
+            let position = match error {
+                GuidParseError::InvalidLength(_) => panic!("{}", error),
+                GuidParseError::InvalidHexDigit { position } => position,
+                GuidParseError::ExpectedChar { position, .. } => position,
+            };
+
+            // Everything before the error is ASCII, so `position` is a char boundary.
+            let found = value[position..].chars().next().unwrap();
+            panic!("{}, found {:?}", error, found)
+        })
+    }
+}
+
+/// Returns the `Guid` identified by a type `signature`, as described by `Guid::of`.
+fn guid_of_signature(signature: &str) -> Guid {
+    const CLASS: &str = "rc(";
+    const DELEGATE: &str = "delegate(";
 
-        let a = ((bytes.next_u32() * 16 + bytes.next_u32()) << 24)
-            + ((bytes.next_u32() * 16 + bytes.next_u32()) << 16)
-            + ((bytes.next_u32() * 16 + bytes.next_u32()) << 8)
-            + bytes.next_u32() * 16
-            + bytes.next_u32();
-        assert!(bytes.next().unwrap() == b'-', "Invalid GUID string");
-        let b = ((bytes.next_u16() * 16 + (bytes.next_u16())) << 8)
-            + bytes.next_u16() * 16
-            + bytes.next_u16();
-        assert!(bytes.next().unwrap() == b'-', "Invalid GUID string");
-        let c = ((bytes.next_u16() * 16 + bytes.next_u16()) << 8)
-            + bytes.next_u16() * 16
-            + bytes.next_u16();
-        assert!(bytes.next().unwrap() == b'-', "Invalid GUID string");
-        let d = bytes.next_u8() * 16 + bytes.next_u8();
-        let e = bytes.next_u8() * 16 + bytes.next_u8();
-        assert!(bytes.next().unwrap() == b'-', "Invalid GUID string");
+    if signature.starts_with(CLASS) && signature.ends_with(')') {
+        // The class name is followed by the signature of the default interface.
+        if let Some(separator) = signature.find(';') {
+            return guid_of_signature(&signature[separator + 1..signature.len() - 1]);
+        }
+    }
 
-        let f = bytes.next_u8() * 16 + bytes.next_u8();
-        let g = bytes.next_u8() * 16 + bytes.next_u8();
-        let h = bytes.next_u8() * 16 + bytes.next_u8();
-        let i = bytes.next_u8() * 16 + bytes.next_u8();
-        let j = bytes.next_u8() * 16 + bytes.next_u8();
-        let k = bytes.next_u8() * 16 + bytes.next_u8();
+    let interface = if signature.starts_with(DELEGATE) && signature.ends_with(')') {
+        &signature[DELEGATE.len()..signature.len() - 1]
+    } else {
+        signature
+    };
 
-        Guid::from_values(a, b, c, [d, e, f, g, h, i, j, k])
+    if let Some(inner) = strip_braces(interface) {
+        if let Ok(guid) = parse(inner.as_bytes(), 0) {
+            return guid;
+        }
     }
+
+    Guid::from_signature_bytes(signature.as_bytes())
 }
 
-trait HexReader {
-    fn next_u8(&mut self) -> u8;
-    fn next_u16(&mut self) -> u16;
-    fn next_u32(&mut self) -> u32;
+/// Formats the `pinterface(...)` signature of a parameterized interface.
+fn parameterized_signature(piid: &Guid, args: &[&str]) -> String {
+    let mut signature = format!("pinterface({{{}}}", piid);
+
+    for arg in args {
+        signature.push(';');
+        signature.push_str(arg);
+    }
+
+    signature.push(')');
+    signature
 }
 
-impl HexReader for std::str::Bytes<'_> {
-    fn next_u8(&mut self) -> u8 {
-        let value = self.next().unwrap();
-        match value {
-            b'0'..=b'9' => value - b'0',
-            b'A'..=b'F' => 10 + value - b'A',
-            b'a'..=b'f' => 10 + value - b'a',
-            _ => panic!("Invalid GUID string"),
+/// Parses the 36 character dashed form of a `Guid` from either UTF-8 bytes or UTF-16
+/// code units. Error positions are reported relative to `offset` so that they point into
+/// the caller's original string.
+fn parse<T: Copy + Into<u32>>(value: &[T], offset: usize) -> Result<Guid, GuidParseError> {
+    if value.len() != Guid::DISPLAY_LEN {
+        return Err(GuidParseError::InvalidLength(value.len()));
+    }
+
+    let mut bits = 0u128;
+
+    for (index, unit) in value.iter().enumerate() {
+        let position = offset + index;
+        // Anything outside of ASCII can be neither a hex digit nor a separator.
+        let byte = u8::try_from(Into::<u32>::into(*unit)).ok();
+
+        if is_separator(index) {
+            if byte != Some(b'-') {
+                return Err(GuidParseError::ExpectedChar {
+                    expected: '-',
+                    position,
+                });
+            }
+        } else {
+            let digit = byte
+                .and_then(hex_digit)
+                .ok_or(GuidParseError::InvalidHexDigit { position })?;
+            bits = (bits << 4) | u128::from(digit);
         }
     }
 
-    fn next_u16(&mut self) -> u16 {
-        self.next_u8().into()
+    Ok(Guid::from_u128_be(bits))
+}
+
+/// Returns `true` if `index` is the position of a `-` in the 36 byte dashed form.
+fn is_separator(index: usize) -> bool {
+    index == 8 || index == 13 || index == 18 || index == 23
+}
+
+/// Returns the contents of `value` between a leading `{` and a trailing `}`, if it has both.
+fn strip_braces(value: &str) -> Option<&str> {
+    if value.len() >= 2 && value.starts_with('{') && value.ends_with('}') {
+        Some(&value[1..value.len() - 1])
+    } else {
+        None
+    }
+}
+
+fn hex_digit(value: u8) -> Option<u8> {
+    match value {
+        b'0'..=b'9' => Some(value - b'0'),
+        b'A'..=b'F' => Some(10 + value - b'A'),
+        b'a'..=b'f' => Some(10 + value - b'a'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_format() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!(value.to_string() == "cff52e04-cca6-4614-a17e-754910c84a99");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid GUID string: unexpected length 35")]
+    fn from_str_invalid_length() {
+        let _ = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A9");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid GUID string: expected '-' at position 8, found 'x'")]
+    fn from_str_invalid_separator() {
+        let _ = Guid::from("CFF52E04xCCA6-4614-A17E-754910C84A99");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid GUID string: expected hex digit at position 35, found 'G'")]
+    fn from_str_invalid_hex_digit() {
+        let _ = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A9G");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid GUID string: expected hex digit at position 1, found 'é'")]
+    fn from_str_non_ascii() {
+        let _ = Guid::from("Cé52E04-CCA6-4614-A17E-754910C84A99");
+    }
+
+    #[test]
+    fn nil() {
+        assert!(Guid::NIL.is_nil());
+        assert!(Guid::NIL == Guid::zeroed());
+        assert!(Guid::default().is_nil());
+        assert!(!Guid::from("00000000-0000-0000-0000-000000000001").is_nil());
+    }
+
+    #[test]
+    fn components() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let (a, b, c, d) = value.components();
+        assert!(a == 0xCFF52E04);
+        assert!(Guid::from_values(a, b, c, d) == value);
+    }
+
+    #[test]
+    fn new_clsid() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!(Guid::new_clsid(value.clone()) == Ok(value));
+        assert!(Guid::new_clsid(Guid::zeroed()) == Err(ReservedGuid::Nil));
+        assert!(
+            Guid::new_clsid(Guid::from("FFFFFFFF-FFFF-FFFF-FFFF-FFFFFFFFFFFF"))
+                == Err(ReservedGuid::Max)
+        );
+    }
+
+    #[test]
+    fn anonymized() {
+        let a = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let b = Guid::from("94EA2B94-E9CC-49E0-80FF-EE64CA8F5B90");
+
+        let anonymous_a = a.anonymized();
+        let anonymous_b = b.anonymized();
+        assert!(anonymous_a == Guid::from("CFF52E04-0000-4000-8000-000000000000"));
+        assert!(anonymous_b == Guid::from("94EA2B94-0000-4000-8000-000000000000"));
+        assert!(anonymous_a.data3 >> 12 == anonymous_b.data3 >> 12);
+
+        // NCS, Microsoft and reserved variants keep one, three and three bits.
+        let ncs = Guid::from("CFF52E04-CCA6-4614-717E-754910C84A99");
+        assert!(ncs.anonymized().data4[0] == 0x00);
+        let microsoft = Guid::from("CFF52E04-CCA6-4614-DF7E-754910C84A99");
+        assert!(microsoft.anonymized().data4[0] == 0xC0);
+        let reserved = Guid::from("CFF52E04-CCA6-4614-FF7E-754910C84A99");
+        assert!(reserved.anonymized().data4[0] == 0xE0);
+    }
+
+    #[test]
+    fn position_in() {
+        let a = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let b = Guid::from("00000000-0000-0000-C000-000000000046");
+        let c = Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B90");
+        let candidates = [a.clone(), b.clone(), Guid::zeroed()];
+
+        assert!(a.matches_any(&candidates));
+        assert!(a.position_in(&candidates) == Some(0));
+
+        assert!(b.matches_any(&candidates));
+        assert!(b.position_in(&candidates) == Some(1));
+
+        assert!(!c.matches_any(&candidates));
+        assert!(c.position_in(&candidates).is_none());
+    }
+
+    #[test]
+    fn from_signature_utf16() {
+        let units: Vec<u16> = "i4".encode_utf16().collect();
+        let value = Guid::from_signature_utf16(&units).unwrap();
+        assert!(value == Guid::from_signature::<i32>());
+
+        assert!(Guid::from_signature_utf16(&[0xD800]).is_err());
+    }
+
+    #[test]
+    fn from_signature_validated() {
+        let signature = b"pinterface({61c17706-2d65-11e0-9ae8-d48564015472};struct(Windows.Foundation.TimeSpan;i8))";
+        assert!(
+            Guid::from_signature_validated(signature)
+                == Ok(Guid::from("604D0C4C-91DE-5C2A-935F-362F13EAF800"))
+        );
+
+        assert!(
+            Guid::from_signature_validated(b"pinterface({61c17706-2d65-11e0-9ae8-d48564015472};i4")
+                == Err(MalformedSignature::Unclosed { position: 10 })
+        );
+
+        assert!(
+            Guid::from_signature_validated(b"pinterface({61c17706-2d65-11e0-9ae8-d48564015472;i4)")
+                == Err(MalformedSignature::UnexpectedClose { position: 51 })
+        );
+
+        assert!(
+            Guid::from_signature_validated(
+                b"pinterface({61c17706-2d65-11e0-9ae8-d48564015472};list(i4))"
+            ) == Err(MalformedSignature::UnknownKeyword { position: 50 })
+        );
+    }
+
+    #[test]
+    fn parameterized_checked() {
+        // The PIID of Windows.Foundation.IReference<T>
+        let piid = Guid::from("61C17706-2D65-11E0-9AE8-D48564015472");
+        assert!(
+            Guid::parameterized_checked(&piid, &["i4"])
+                == Ok(Guid::from("548CEFBD-BC8A-5FA0-8DF2-957440FC8BF4"))
+        );
+
+        assert!(Guid::parameterized_checked(&Guid::NIL, &["i4"]) == Err(BadPiid::Nil));
+
+        let piid = Guid::from("61C17706-2D65-11E0-1AE8-D48564015472");
+        assert!(Guid::parameterized_checked(&piid, &["i4"]) == Err(BadPiid::InvalidVariant));
+
+        let piid = Guid::from("61C17706-2D65-01E0-9AE8-D48564015472");
+        assert!(Guid::parameterized_checked(&piid, &["i4"]) == Err(BadPiid::InvalidVersion(0)));
+    }
+
+    #[cfg(feature = "heapless")]
+    #[test]
+    fn to_heapless() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!(guid.to_heapless().as_str() == guid.to_string());
+    }
+
+    #[test]
+    fn bucket() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+
+        for n in 1..64 {
+            assert!(guid.bucket(n) < n);
+            assert!(guid.bucket(n) == guid.clone().bucket(n));
+        }
+
+        assert!(guid.bucket(0) == 0);
+        assert!(guid.bucket(1 << 16) == 0x4A99 ^ 0x4614);
+    }
+
+    #[test]
+    fn masked() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let mut mask = [0; 16];
+        mask[0] = 0xff;
+
+        let bytes = guid.masked(&mask).to_bytes_le();
+        assert!(bytes[0] == guid.to_bytes_le()[0]);
+        assert!(bytes[1..].iter().all(|byte| *byte == 0));
+        assert!(guid.masked(&mask) == Guid::from("00000004-0000-0000-0000-000000000000"));
+
+        // Index 3 is the first byte of the text.
+        let mut mask = [0; 16];
+        mask[3] = 0xff;
+        assert!(guid.masked(&mask) == Guid::from("CF000000-0000-0000-0000-000000000000"));
+    }
+
+    #[test]
+    fn canonicalize_all() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+
+        let (guids, failed) = Guid::canonicalize_all(&[
+            "CFF52E04-CCA6-4614-A17E-754910C84A99",
+            "not a guid",
+            "cff52e04-cca6-4614-a17e-754910c84a99",
+            "{CFF52E04-CCA6-4614-A17E-754910C84A99}",
+            "CFF52E04-CCA6-4614-A17E-754910C84A9",
+            "",
+            "{cff52e04-CCA6-4614-a17e-754910C84A99}",
+        ]);
+
+        assert!(guids == [guid.clone(), guid.clone(), guid.clone(), guid]);
+        assert!(failed == [1, 4, 5]);
+
+        assert!(Guid::canonicalize_all(&[]) == (vec![], vec![]));
+    }
+
+    #[test]
+    fn from_registry_key() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+
+        for key in &[
+            "CLSID\\{CFF52E04-CCA6-4614-A17E-754910C84A99}",
+            "Interface\\{CFF52E04-CCA6-4614-A17E-754910C84A99}",
+            "AppID\\{cff52e04-cca6-4614-a17e-754910c84a99}",
+            "clsid\\{CFF52E04-CCA6-4614-A17E-754910C84A99}",
+            "{CFF52E04-CCA6-4614-A17E-754910C84A99}",
+            "CFF52E04-CCA6-4614-A17E-754910C84A99",
+        ] {
+            assert!(Guid::from_registry_key(key) == Ok(guid.clone()));
+        }
+
+        assert!(
+            Guid::from_registry_key("CLSID\\{CFF52E04-CCA6-4614-A17E-754910C84A9X}")
+                == Err(GuidParseError::InvalidHexDigit { position: 42 })
+        );
+        assert!(
+            Guid::from_registry_key("TypeLib\\{CFF52E04-CCA6-4614-A17E-754910C84A99}")
+                == Err(GuidParseError::InvalidLength(46))
+        );
+        assert!(
+            Guid::from_registry_key("CLSID\\{CFF52E04-CCA6-4614-A17E-754910C84A9}")
+                == Err(GuidParseError::InvalidLength(43))
+        );
+    }
+
+    #[test]
+    fn ole_string() {
+        let guid = Guid::from("cff52e04-cca6-4614-a17e-754910c84a99");
+        let value = guid.to_ole_string();
+        assert!(value == "{CFF52E04-CCA6-4614-A17E-754910C84A99}");
+        assert!(Guid::from_ole_string(&value) == Ok(guid));
+    }
+
+    #[test]
+    fn ct_eq() {
+        let a = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let b = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A98");
+        assert!(a.ct_eq(&a.clone()) == (a == a.clone()));
+        assert!(a.ct_eq(&b) == (a == b));
+        assert!(!a.ct_eq(&b));
+    }
+
+    #[test]
+    fn sql_bytes() {
+        let text = "CFF52E04-CCA6-4614-A17E-754910C84A99";
+        let guid = Guid::from(text);
+        let uuid = uuid::Uuid::parse_str(text).unwrap();
+
+        assert!(guid.to_sql_bytes() == *uuid.as_bytes());
+        assert!(Guid::from_sql_bytes(*uuid.as_bytes()) == guid);
+    }
+
+    #[test]
+    fn fmt_fast() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let mut buffer = [0; Guid::DISPLAY_LEN];
+        guid.fmt_fast(&mut buffer);
+        assert!(buffer[..] == b"cff52e04-cca6-4614-a17e-754910c84a99"[..]);
+        assert!(buffer[..] == guid.to_string().as_bytes()[..]);
+
+        assert!(format!("{:>40}", guid) == "    cff52e04-cca6-4614-a17e-754910c84a99");
+        assert!(format!("{:*<37}", guid) == "cff52e04-cca6-4614-a17e-754910c84a99*");
+    }
+
+    #[test]
+    fn display_len() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!(guid.to_string().len() == Guid::DISPLAY_LEN);
+        assert!(guid.to_registry_string().len() == Guid::BRACED_LEN);
+    }
+
+    #[test]
+    fn explain_parameterized() {
+        // The PIID of Windows.Foundation.IReference<T>
+        let piid = Guid::from("61C17706-2D65-11E0-9AE8-D48564015472");
+        assert!(
+            Guid::explain_parameterized(&piid, &["i4"])
+                == "pinterface({61c17706-2d65-11e0-9ae8-d48564015472};i4)"
+        );
+    }
+
+    #[test]
+    fn rfc_bytes() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let rfc = guid.to_rfc_bytes();
+        let le = guid.to_bytes_le();
+
+        assert!(rfc[..4] == [0xCF, 0xF5, 0x2E, 0x04]);
+        assert!(rfc[..8].iter().zip(&le[..8]).all(|(a, b)| a != b));
+        assert!(rfc[8..] == le[8..]);
+        assert!(Guid::from_rfc_bytes(rfc) == guid);
+        assert!(rfc == guid.to_u128_be().to_be_bytes());
+    }
+
+    #[test]
+    fn signature_hasher() {
+        let signature = "pinterface({61c17706-2d65-11e0-9ae8-d48564015472};i4)";
+
+        let mut hasher = SignatureHasher::new();
+        hasher.push(b"pinterface(");
+        hasher.push(b"{61c17706-2d65-11e0-9ae8-d48564015472}");
+        hasher.push(b";");
+        hasher.push(b"i4");
+        hasher.push(b")");
+        let value = hasher.finalize();
+
+        assert!(value == Guid::from_signature_bytes(signature.as_bytes()));
+        assert!(value == Guid::from("548CEFBD-BC8A-5FA0-8DF2-957440FC8BF4"));
+    }
+
+    #[test]
+    fn loosely_equals() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!(value.loosely_equals("CFF52E04-CCA6-4614-A17E-754910C84A99"));
+        assert!(value.loosely_equals("{CFF52E04-CCA6-4614-A17E-754910C84A99}"));
+        assert!(value.loosely_equals("cff52e04-cca6-4614-a17e-754910c84a99"));
+        assert!(value.loosely_equals("cff52e04cca64614a17e754910c84a99"));
+        assert!(value.loosely_equals("{CFF52E04CCA64614A17E754910C84A99}"));
+
+        assert!(!value.loosely_equals("CFF52E04-CCA6-4614-A17E-754910C84A98"));
+        assert!(!value.loosely_equals("CFF52E04-CCA6-4614-A17E-754910C84A9"));
+        assert!(!value.loosely_equals("CFF52E04-CCA6-4614-A17E-754910C84A990"));
+        assert!(!value.loosely_equals("{CFF52E04-CCA6-4614-A17E-754910C84A99"));
+        assert!(!value.loosely_equals("CFF52E04-CCA6-4614-A17E-754910C84AXX"));
+    }
+
+    #[test]
+    fn eq_ascii() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!(value.eq_ascii(b"CFF52E04-CCA6-4614-A17E-754910C84A99"));
+        assert!(value.eq_ascii(b"cff52e04-cca6-4614-a17e-754910c84a99"));
+        assert!(!value.eq_ascii(b"cff52e04-cca6-4614-a17e-754910c84a98"));
+        assert!(!value.eq_ascii(b"cff52e04cca6-4614-a17e-754910c84a99-"));
+    }
+
+    #[test]
+    fn env_safe() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let text = value.to_env_safe();
+        assert!(text == "cff52e04cca64614a17e754910c84a99");
+        assert!(text
+            .bytes()
+            .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b)));
+        assert!(Guid::from_env_safe(&text) == Ok(value));
+
+        assert!(
+            Guid::from_env_safe("cff52e04-cca6-4614-a17e-754910c84a99")
+                == Err(GuidParseError::InvalidLength(36))
+        );
+        assert!(
+            Guid::from_env_safe("cff52e04cca64614a17e754910c84a9g")
+                == Err(GuidParseError::InvalidHexDigit { position: 31 })
+        );
+        assert!(Guid::zeroed().to_env_safe() == "00000000000000000000000000000000");
+    }
+
+    #[test]
+    fn xor() {
+        let a = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let b = Guid::from("00000000-0000-0000-C000-000000000046");
+        assert!(a.xor(&b) != a);
+        assert!(a.xor(&b).xor(&b) == a);
+        assert!(a.xor(&a) == Guid::zeroed());
+    }
+
+    #[test]
+    fn read_write_le() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let mut cursor = std::io::Cursor::new(Vec::new());
+        value.write_le(&mut cursor).unwrap();
+
+        assert!(
+            cursor.get_ref()[..]
+                == [
+                    0x04, 0x2E, 0xF5, 0xCF, 0xA6, 0xCC, 0x14, 0x46, 0xA1, 0x7E, 0x75, 0x49, 0x10,
+                    0xC8, 0x4A, 0x99
+                ]
+        );
+
+        cursor.set_position(0);
+        assert!(Guid::read_le(&mut cursor).unwrap() == value);
+        assert!(Guid::read_le(&mut cursor).is_err());
+    }
+
+    #[test]
+    fn const_constructors() {
+        // Binding to `const` items forces compile time evaluation, so any of these
+        // constructors losing its `const` qualifier fails to build.
+        const ZEROED: Guid = Guid::zeroed();
+        const VALUES: Guid = Guid::from_values(
+            0xCFF52E04,
+            0xCCA6,
+            0x4614,
+            [0xA1, 0x7E, 0x75, 0x49, 0x10, 0xC8, 0x4A, 0x99],
+        );
+        const BYTES: Guid = Guid::from_bytes_le(VALUES.to_bytes_le());
+        const XOR: Guid = VALUES.xor(&VALUES);
+
+        // Like `static_assertions::const_assert!`, a false condition makes the array
+        // length overflow and fails to build.
+        macro_rules! const_assert {
+            ($condition:expr) => {
+                const _: [(); 0 - !$condition as usize] = [];
+            };
+        }
+
+        const_assert!(ZEROED.data1 == 0 && ZEROED.data4[7] == 0);
+        const_assert!(VALUES.data1 == 0xCFF52E04 && VALUES.data4[7] == 0x99);
+        const_assert!(BYTES.data2 == VALUES.data2 && BYTES.data3 == VALUES.data3);
+        const_assert!(XOR.data1 == 0 && XOR.data4[0] == 0);
+
+        assert!(ZEROED == Guid::default());
+        assert!(VALUES == Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99"));
+        assert!(BYTES == VALUES);
+        assert!(XOR == ZEROED);
+    }
+
+    #[test]
+    fn from_str() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!("CFF52E04-CCA6-4614-A17E-754910C84A99".parse() == Ok(value.clone()));
+        assert!("{cff52e04-cca6-4614-a17e-754910c84a99}".parse() == Ok(value));
+
+        assert!(
+            "CFF52E04-CCA6-46X4-A17E-754910C84A99".parse::<Guid>()
+                == Err(GuidParseError::InvalidHexDigit { position: 16 })
+        );
+
+        assert!(
+            "{CFF52E04-CCA6-46X4-A17E-754910C84A99}".parse::<Guid>()
+                == Err(GuidParseError::InvalidHexDigit { position: 17 })
+        );
+
+        assert!(
+            "{CFF52E04-CCA6-4614-A17E-754910C84A99".parse::<Guid>()
+                == Err(GuidParseError::InvalidLength(37))
+        );
+
+        assert!(
+            "{CFF52E04-CCA6-4614-A17E-754910C84A9}".parse::<Guid>()
+                == Err(GuidParseError::InvalidLength(37))
+        );
+    }
+
+    #[test]
+    fn string_conversions() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let text = String::from(value.clone());
+        assert!(text == "cff52e04-cca6-4614-a17e-754910c84a99");
+        assert!(Guid::try_from(text) == Ok(value));
+
+        assert!(
+            Guid::try_from(String::from("CFF52E04-CCA6-4614-A17E"))
+                == Err(GuidParseError::InvalidLength(23))
+        );
+    }
+
+    #[test]
+    fn to_string_upper() {
+        let value = Guid::from("cff52e04-cca6-4614-a17e-754910c84a99");
+        let upper = value.to_string_upper();
+        let lower = value.to_string();
+        assert!(upper == "CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!(upper != lower);
+        assert!(upper.eq_ignore_ascii_case(&lower));
+    }
+
+    #[test]
+    fn registry_string() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let text = value.to_registry_string();
+        assert!(text == "{CFF52E04-CCA6-4614-A17E-754910C84A99}");
+        assert!(Guid::from_registry_string(&text) == Ok(value.clone()));
+
+        assert!(Guid::from_registry_string("{cff52e04-cca6-4614-a17e-754910c84a99}") == Ok(value));
+
+        assert!(
+            Guid::from_registry_string("CFF52E04-CCA6-4614-A17E-754910C84A99")
+                == Err(GuidParseError::InvalidLength(36))
+        );
+
+        assert!(
+            Guid::from_registry_string("{CFF52E04-CCA6-4614-A17E-754910C84A99)")
+                == Err(GuidParseError::ExpectedChar {
+                    expected: '}',
+                    position: 37
+                })
+        );
+    }
+
+    #[test]
+    fn is_valid_str() {
+        assert!(Guid::is_valid_str("CFF52E04-CCA6-4614-A17E-754910C84A99"));
+        assert!(Guid::is_valid_str("{cff52e04-cca6-4614-a17e-754910c84a99}"));
+
+        assert!(!Guid::is_valid_str(""));
+        assert!(!Guid::is_valid_str("{"));
+        assert!(!Guid::is_valid_str("CFF52E04-CCA6-4614-A17E-754910C84A9"));
+        assert!(!Guid::is_valid_str("{CFF52E04-CCA6-4614-A17E-754910C84A99"));
+        assert!(!Guid::is_valid_str("CFF52E04-CCA6-4614-A17E-754910C84A9G"));
+        assert!(!Guid::is_valid_str("CFF52E04+CCA6-4614-A17E-754910C84A99"));
+    }
+
+    #[test]
+    fn from_utf16() {
+        let mut units: Vec<u16> = "CFF52E04-CCA6-4614-A17E-754910C84A99"
+            .encode_utf16()
+            .collect();
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!(Guid::from_utf16(&units) == Ok(value));
+
+        // A FULLWIDTH DIGIT ZERO is not an ASCII hex digit.
+        units[3] = 0xFF10;
+        assert!(Guid::from_utf16(&units) == Err(GuidParseError::InvalidHexDigit { position: 3 }));
+    }
+
+    #[test]
+    fn u128() {
+        let value = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let bits = 0xCFF52E04_CCA6_4614_A17E_754910C84A99;
+
+        assert!(Guid::from_u128_be(bits) == value);
+        assert!(Guid::from_u128_le(bits) != value);
+        assert!(value.to_u128_be() == bits);
+        assert!(value.to_u128_le() != bits);
+
+        assert!(Guid::from_u128_be(value.to_u128_be()) == value);
+        assert!(Guid::from_u128_le(value.to_u128_le()) == value);
+        assert!(Guid::from_u128_le(bits).to_u128_le() == bits);
+
+        assert!(value.to_u128_le() == u128::from_le_bytes(value.to_bytes_le()));
+        assert!(Guid::from_u128_le(bits) == Guid::from_bytes_le(bits.to_le_bytes()));
+    }
+
+    #[test]
+    fn sort_key() {
+        let mut by_key = vec![
+            Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99"),
+            Guid::from("00000000-0000-0000-C000-000000000046"),
+            Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B90"),
+            Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B8F"),
+            Guid::from("94EA2B94-E9CD-49E0-C0FF-EE64CA8F5B8F"),
+            Guid::from("00000001-0000-0000-0000-000000000000"),
+        ];
+        let mut by_ord = by_key.clone();
+
+        by_key.sort_by_key(Guid::sort_key);
+        by_ord.sort();
+        assert!(by_key == by_ord);
+    }
+
+    #[cfg(feature = "arbitrary")]
+    #[test]
+    fn arbitrary() {
+        use arbitrary::Arbitrary;
+
+        let bytes = [
+            0x04, 0x2E, 0xF5, 0xCF, 0xA6, 0xCC, 0x14, 0x46, 0xA1, 0x7E, 0x75, 0x49, 0x10, 0xC8,
+            0x4A, 0x99,
+        ];
+
+        let mut input = arbitrary::Unstructured::new(&bytes);
+        assert!(Guid::arbitrary(&mut input).unwrap() == Guid::from_bytes_le(bytes));
+    }
+
+    #[test]
+    fn with_fields() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let value = guid.with_data3(0x5614);
+        assert!(value == Guid::from("CFF52E04-CCA6-5614-A17E-754910C84A99"));
+
+        let (data1, data2, _, data4) = guid.components();
+        assert!(value.components() == (data1, data2, 0x5614, data4));
+
+        assert!(
+            guid.with_data1(0)
+                .with_data2(0)
+                .with_data3(0)
+                .with_data4([0; 8])
+                == Guid::NIL
+        );
+    }
+
+    #[test]
+    fn of() {
+        macro_rules! signature_type {
+            ($name:ident, $signature:literal) => {
+                #[repr(transparent)]
+                #[derive(Clone)]
+                struct $name(crate::RawPtr);
+
+                unsafe impl AbiTransferable for $name {
+                    type Abi = crate::RawPtr;
+
+                    fn get_abi(&self) -> Self::Abi {
+                        self.0
+                    }
+
+                    fn set_abi(&mut self) -> *mut Self::Abi {
+                        &mut self.0
+                    }
+                }
+
+                unsafe impl RuntimeType for $name {
+                    fn signature() -> String {
+                        $signature.to_owned()
+                    }
+                }
+            };
+        }
+
+        // Windows.Foundation.IStringable
+        signature_type!(Interface, "{96369f54-8eb6-48f0-abce-c1b211e627c3}");
+        // Windows.Foundation.AsyncActionCompletedHandler
+        signature_type!(Delegate, "delegate({a4ed5c81-76c9-40bd-8be6-b1d90fb20ae7})");
+        // Windows.Foundation.Uri
+        signature_type!(
+            Class,
+            "rc(Windows.Foundation.Uri;{9e365e57-48b2-4160-956f-c7385120bbfc})"
+        );
+        // Windows.Foundation.Collections.StringMap
+        signature_type!(
+            GenericClass,
+            "rc(Windows.Foundation.Collections.StringMap;pinterface({3c2925fe-8519-45c1-aa79-197b6718c1c1};string;string))"
+        );
+        // Windows.Foundation.IReference<i32>
+        signature_type!(
+            Parameterized,
+            "pinterface({61c17706-2d65-11e0-9ae8-d48564015472};i4)"
+        );
+
+        assert!(Guid::of::<Guid>() == Guid::from("DF13FE78-A361-5FE3-9C75-C55D71F27737"));
+        assert!(Guid::of::<i32>() == Guid::from("DDDCA6FF-9A5D-5E9B-8E44-981BE907AFC0"));
+        assert!(Guid::of::<i32>() == Guid::from_signature::<i32>());
+
+        assert!(Guid::of::<Interface>() == Guid::from("96369F54-8EB6-48F0-ABCE-C1B211E627C3"));
+        assert!(Guid::of::<Delegate>() == Guid::from("A4ED5C81-76C9-40BD-8BE6-B1D90FB20AE7"));
+        assert!(Guid::of::<Class>() == Guid::from("9E365E57-48B2-4160-956F-C7385120BBFC"));
+        assert!(Guid::of::<GenericClass>() == Guid::from("F6D1F700-49C2-52AE-8154-826F9908773C"));
+        assert!(Guid::of::<Parameterized>() == Guid::from("548CEFBD-BC8A-5FA0-8DF2-957440FC8BF4"));
+    }
+
+    #[test]
+    fn from_name() {
+        let dns = Guid::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        let value = Guid::from_name(&dns, b"www.example.com");
+        assert!(value == Guid::from("2ed6657d-e927-568b-95e1-2665a8aea6a2"));
+    }
+
+    #[test]
+    fn parse_or_derive() {
+        let dns = Guid::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+
+        let value = Guid::parse_or_derive("CFF52E04-CCA6-4614-A17E-754910C84A99", &dns);
+        assert!(value == Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99"));
+
+        let value = Guid::parse_or_derive("www.example.com", &dns);
+        assert!(value == Guid::from("2ed6657d-e927-568b-95e1-2665a8aea6a2"));
+        assert!(value == Guid::parse_or_derive("www.example.com", &dns));
+    }
+
+    #[cfg(feature = "sha2")]
+    #[test]
+    fn from_name_sha256() {
+        let dns = Guid::from("6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+        let value = Guid::from_name_sha256(&dns, b"www.example.com");
+        assert!(value == Guid::from("5c146b14-3c52-8afd-938a-375d0df1fbf6"));
     }
 
-    fn next_u32(&mut self) -> u32 {
-        self.next_u8().into()
+    #[cfg(feature = "rand")]
+    #[test]
+    fn new_v1() {
+        let node = [0x01, 0x23, 0x45, 0x67, 0x89, 0xAB];
+        let value = Guid::new_v1(node);
+        assert!(value.data3 >> 12 == 1);
+        assert!(value.data4[0] & 0xC0 == 0x80);
+        assert!(value.data4[2..] == node);
     }
 }
@@ -0,0 +1,55 @@
+use crate::*;
+use std::marker::PhantomData;
+
+/// A borrowed `Guid` passed by pointer, modeling the COM `REFGUID` and `REFIID` parameter
+/// types which are `const GUID*` in C.
+///
+/// The pointer is only valid for the lifetime `'a` of the borrowed `Guid`, so callees
+/// must not hold on to the pointer beyond the call it was passed to.
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct GuidRef<'a>(*const Guid, PhantomData<&'a Guid>);
+
+impl<'a> GuidRef<'a> {
+    /// Borrows the `Guid` for the lifetime `'a`.
+    pub fn new(guid: &'a Guid) -> Self {
+        Self(guid, PhantomData)
+    }
+
+    /// The pointer to the borrowed `Guid`.
+    pub fn as_ptr(&self) -> *const Guid {
+        self.0
+    }
+}
+
+impl<'a> From<&'a Guid> for GuidRef<'a> {
+    fn from(guid: &'a Guid) -> Self {
+        Self::new(guid)
+    }
+}
+
+unsafe impl<'a> AbiTransferable for GuidRef<'a> {
+    type Abi = *const Guid;
+
+    fn get_abi(&self) -> Self::Abi {
+        self.0
+    }
+
+    fn set_abi(&mut self) -> *mut Self::Abi {
+        &mut self.0 as *mut Self::Abi
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_guid() {
+        let guid = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let value = GuidRef::from(&guid);
+        assert!(std::ptr::eq(value.as_ptr(), &guid));
+        assert!(std::ptr::eq(value.get_abi(), &guid));
+        assert!(unsafe { *value.into_abi() == guid });
+    }
+}
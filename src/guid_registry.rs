@@ -0,0 +1,103 @@
+use crate::Guid;
+use std::collections::HashMap;
+
+/// A bidirectional table mapping known `Guid`s to small `u16` codes.
+///
+/// Each `Guid` and each code may only be registered once, so lookups in either
+/// direction are unambiguous.
+#[derive(Default)]
+pub struct GuidRegistry {
+    entries: Vec<(Guid, u16)>,
+    by_guid: HashMap<Guid, usize>,
+    by_code: HashMap<u16, usize>,
+}
+
+impl GuidRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the `guid` with the given `code`, failing if either is already registered.
+    pub fn register(&mut self, guid: Guid, code: u16) -> Result<(), DuplicateRegistration> {
+        if self.by_guid.contains_key(&guid) {
+            return Err(DuplicateRegistration::Guid(guid));
+        }
+
+        if self.by_code.contains_key(&code) {
+            return Err(DuplicateRegistration::Code(code));
+        }
+
+        let index = self.entries.len();
+        self.by_guid.insert(guid.clone(), index);
+        self.by_code.insert(code, index);
+        self.entries.push((guid, code));
+        Ok(())
+    }
+
+    /// The code registered for the `guid`, if any.
+    pub fn code_for(&self, guid: &Guid) -> Option<u16> {
+        self.by_guid.get(guid).map(|index| self.entries[*index].1)
+    }
+
+    /// The `Guid` registered for the `code`, if any.
+    pub fn guid_for(&self, code: u16) -> Option<&Guid> {
+        self.by_code.get(&code).map(|index| &self.entries[*index].0)
+    }
+
+    /// All of the registered entries in the order they were registered.
+    pub fn entries(&self) -> &[(Guid, u16)] {
+        &self.entries
+    }
+}
+
+/// The error returned by `GuidRegistry::register` when an entry is already registered.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DuplicateRegistration {
+    /// The `Guid` is already registered with another code.
+    Guid(Guid),
+    /// The code is already registered for another `Guid`.
+    Code(u16),
+}
+
+impl std::fmt::Display for DuplicateRegistration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Guid(guid) => write!(f, "The GUID {} is already registered", guid),
+            Self::Code(code) => write!(f, "The code {} is already registered", code),
+        }
+    }
+}
+
+impl std::error::Error for DuplicateRegistration {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register() {
+        let a = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let b = Guid::from("00000000-0000-0000-C000-000000000046");
+        let c = Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B90");
+
+        let mut registry = GuidRegistry::new();
+        assert!(registry.register(a.clone(), 1).is_ok());
+        assert!(registry.register(b.clone(), 2).is_ok());
+
+        assert!(registry.code_for(&a) == Some(1));
+        assert!(registry.code_for(&b) == Some(2));
+        assert!(registry.code_for(&c).is_none());
+
+        assert!(registry.guid_for(1) == Some(&a));
+        assert!(registry.guid_for(2) == Some(&b));
+        assert!(registry.guid_for(3).is_none());
+
+        assert!(registry.register(c.clone(), 1) == Err(DuplicateRegistration::Code(1)));
+        assert!(registry.register(a.clone(), 3) == Err(DuplicateRegistration::Guid(a.clone())));
+        assert!(registry.code_for(&c).is_none());
+        assert!(registry.guid_for(3).is_none());
+
+        assert!(registry.entries() == [(a, 1), (b, 2)]);
+    }
+}
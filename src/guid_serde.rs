@@ -0,0 +1,111 @@
+//! Helpers for serializing a `Guid` field with `#[serde(with = "...")]`, so that each
+//! field can choose between the string and byte forms independently.
+
+/// Serializes a `Guid` as its canonical lowercase dashed string, for example
+/// `cff52e04-cca6-4614-a17e-754910c84a99`.
+///
+/// Deserialization also accepts uppercase and brace-wrapped strings.
+pub mod as_string {
+    use crate::Guid;
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(value: &Guid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Guid, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Serializes a `Guid` as its 16 bytes in the big-endian order of `Guid::to_rfc_bytes`.
+pub mod as_bytes {
+    use crate::Guid;
+    use serde::{de, Deserializer, Serializer};
+    use std::fmt;
+
+    pub fn serialize<S: Serializer>(value: &Guid, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&value.to_rfc_bytes())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Guid, D::Error> {
+        deserializer.deserialize_bytes(BytesVisitor)
+    }
+
+    struct BytesVisitor;
+
+    impl<'de> de::Visitor<'de> for BytesVisitor {
+        type Value = Guid;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "16 bytes")
+        }
+
+        fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<Guid, E> {
+            let mut bytes = [0; 16];
+
+            if value.len() != bytes.len() {
+                return Err(E::invalid_length(value.len(), &self));
+            }
+
+            bytes.copy_from_slice(value);
+            Ok(Guid::from_rfc_bytes(bytes))
+        }
+
+        // Formats without a native byte type, such as JSON, use a sequence instead.
+        fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Guid, A::Error> {
+            let mut bytes = [0; 16];
+
+            for (index, byte) in bytes.iter_mut().enumerate() {
+                *byte = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(index, &self))?;
+            }
+
+            if seq.next_element::<u8>()?.is_some() {
+                return Err(de::Error::invalid_length(17, &self));
+            }
+
+            Ok(Guid::from_rfc_bytes(bytes))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Guid;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Record {
+        #[serde(with = "super::as_string")]
+        text: Guid,
+        #[serde(with = "super::as_bytes")]
+        bytes: Guid,
+    }
+
+    #[test]
+    fn json() {
+        let record = Record {
+            text: Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99"),
+            bytes: Guid::from("00000000-0000-0000-C000-000000000046"),
+        };
+
+        let json = serde_json::to_string(&record).unwrap();
+        assert!(
+            json == r#"{"text":"cff52e04-cca6-4614-a17e-754910c84a99","bytes":[0,0,0,0,0,0,0,0,192,0,0,0,0,0,0,70]}"#
+        );
+        assert!(serde_json::from_str::<Record>(&json).unwrap() == record);
+
+        let json = r#"{"text":"{CFF52E04-CCA6-4614-A17E-754910C84A99}","bytes":[0,0,0,0,0,0,0,0,192,0,0,0,0,0,0,70]}"#;
+        assert!(serde_json::from_str::<Record>(json).unwrap() == record);
+
+        let json = r#"{"text":"cff52e04","bytes":[0,0,0,0,0,0,0,0,192,0,0,0,0,0,0,70]}"#;
+        assert!(serde_json::from_str::<Record>(json).is_err());
+
+        let json = r#"{"text":"cff52e04-cca6-4614-a17e-754910c84a99","bytes":[0,0,0]}"#;
+        assert!(serde_json::from_str::<Record>(json).is_err());
+    }
+}
@@ -0,0 +1,127 @@
+use crate::Guid;
+use std::collections::HashSet;
+
+/// Extension methods for working with slices of `Guid`s, such as the lists of
+/// interface IDs gathered from metadata.
+pub trait GuidSliceExt {
+    /// Returns the `Guid`s with any duplicates removed, keeping the first occurrence of
+    /// each so that the original order is preserved.
+    fn dedup_stable(&self) -> Vec<Guid>;
+
+    /// Sorts the `Guid`s in canonical order, comparing `data1`, `data2`, `data3` and
+    /// then `data4`. This is the same order as their textual form.
+    fn sort_iids(&mut self);
+
+    /// Searches `Guid`s previously sorted with `sort_iids` for the `needle`.
+    ///
+    /// Like `slice::binary_search`, this returns the index of the match or the index
+    /// where the `needle` could be inserted to keep the `Guid`s sorted.
+    fn binary_search_iid(&self, needle: &Guid) -> Result<usize, usize>;
+
+    /// Returns the `Guid`s that are also in `other`, in the order they appear in `self`.
+    fn intersect(&self, other: &[Guid]) -> Vec<Guid>;
+
+    /// Returns the `Guid`s that are not in `other`, in the order they appear in `self`.
+    fn difference(&self, other: &[Guid]) -> Vec<Guid>;
+}
+
+impl GuidSliceExt for [Guid] {
+    fn dedup_stable(&self) -> Vec<Guid> {
+        let mut seen = HashSet::with_capacity(self.len());
+
+        self.iter()
+            .filter(|guid| seen.insert(*guid))
+            .cloned()
+            .collect()
+    }
+
+    fn sort_iids(&mut self) {
+        self.sort_unstable();
+    }
+
+    fn binary_search_iid(&self, needle: &Guid) -> Result<usize, usize> {
+        self.binary_search(needle)
+    }
+
+    fn intersect(&self, other: &[Guid]) -> Vec<Guid> {
+        let other: HashSet<&Guid> = other.iter().collect();
+
+        self.iter()
+            .filter(|guid| other.contains(guid))
+            .cloned()
+            .collect()
+    }
+
+    fn difference(&self, other: &[Guid]) -> Vec<Guid> {
+        let other: HashSet<&Guid> = other.iter().collect();
+
+        self.iter()
+            .filter(|guid| !other.contains(guid))
+            .cloned()
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedup_stable() {
+        let a = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let b = Guid::from("00000000-0000-0000-C000-000000000046");
+        let c = Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B90");
+
+        let values = [
+            b.clone(),
+            a.clone(),
+            b.clone(),
+            c.clone(),
+            a.clone(),
+            c.clone(),
+        ];
+
+        assert!(values.dedup_stable() == [b, a, c]);
+        assert!([].dedup_stable().is_empty());
+    }
+
+    #[test]
+    fn sort_and_search() {
+        let a = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let b = Guid::from("00000000-0000-0000-C000-000000000046");
+        let c = Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B90");
+        let d = Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B91");
+
+        let mut table = [a.clone(), c.clone(), b.clone()];
+        table.sort_iids();
+        assert!(table == [b, c, a.clone()]);
+
+        assert!(table.binary_search_iid(&a) == Ok(2));
+        assert!(table.binary_search_iid(&d) == Err(2));
+        assert!(table.binary_search_iid(&Guid::zeroed()) == Err(0));
+    }
+
+    #[test]
+    fn intersect_and_difference() {
+        let a = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        let b = Guid::from("00000000-0000-0000-C000-000000000046");
+        let c = Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B90");
+        let d = Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B91");
+
+        // Overlapping
+        let values = [a.clone(), b.clone(), c.clone()];
+        let other = [c.clone(), d.clone(), a.clone()];
+        assert!(values.intersect(&other) == [a.clone(), c.clone()]);
+        assert!(values.difference(&other) == [b.clone()]);
+
+        // Disjoint
+        let other = [d.clone()];
+        assert!(values.intersect(&other).is_empty());
+        assert!(values.difference(&other) == values);
+
+        // Subset
+        let other = [d, c.clone(), b.clone(), a.clone()];
+        assert!(values.intersect(&other) == values);
+        assert!(values.difference(&other).is_empty());
+    }
+}
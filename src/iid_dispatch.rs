@@ -0,0 +1,86 @@
+use crate::Guid;
+
+/// A compile-time perfect hash table mapping a fixed set of interface IDs to indices,
+/// such as the interfaces a `QueryInterface` implementation supports.
+///
+/// Use the `guid_table!` macro to build one. The keys are the `Guid`s in the `u128`
+/// form of `Guid::to_u128_be`, so they can be hashed at compile time by `phf`.
+pub struct IidDispatch {
+    map: phf::Map<u128, usize>,
+}
+
+impl IidDispatch {
+    #[doc(hidden)]
+    pub const fn from_map(map: phf::Map<u128, usize>) -> Self {
+        Self { map }
+    }
+
+    /// The index of the `iid`, if it is in the table.
+    pub fn get(&self, iid: &Guid) -> Option<usize> {
+        self.map.get(&iid.to_u128_be()).cloned()
+    }
+
+    /// The number of interface IDs in the table.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    /// Returns `true` if the table has no interface IDs.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+}
+
+/// Builds an `IidDispatch` at compile time from the interface IDs it maps to indices.
+///
+/// Each interface ID is a `u128` literal with the `u128` suffix, written in textual order
+/// as with `Guid::from_u128_be`. `phf` needs literal keys to hash them at compile time,
+/// which rules out `Guid` values. The calling crate doesn't need to depend on `phf`.
+///
+/// ```
+/// static TABLE: winrt::IidDispatch = winrt::guid_table! {
+///     0x00000000_0000_0000_C000_000000000046u128 => 0,
+///     0xAF86E2E0_B12D_4C6A_9C5A_D7AA65101E90u128 => 1,
+/// };
+///
+/// let inspectable = winrt::Guid::from("AF86E2E0-B12D-4C6A-9C5A-D7AA65101E90");
+/// assert_eq!(TABLE.get(&inspectable), Some(1));
+/// assert_eq!(TABLE.get(&winrt::Guid::zeroed()), None);
+/// ```
+#[macro_export]
+macro_rules! guid_table {
+    ($($iid:literal => $index:expr),* $(,)?) => {
+        $crate::IidDispatch::from_map({
+            use $crate::phf;
+            phf::phf_map! { $($iid => $index),* }
+        })
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    static TABLE: IidDispatch = guid_table! {
+        0x00000000_0000_0000_C000_000000000046u128 => 0,
+        0xAF86E2E0_B12D_4C6A_9C5A_D7AA65101E90u128 => 1,
+        0x94EA2B94_E9CC_49E0_C0FF_EE64CA8F5B90u128 => 2,
+    };
+
+    #[test]
+    fn dispatch() {
+        assert!(TABLE.len() == 3);
+
+        let unknown = Guid::from("00000000-0000-0000-C000-000000000046");
+        assert!(TABLE.get(&unknown) == Some(0));
+
+        let inspectable = Guid::from("AF86E2E0-B12D-4C6A-9C5A-D7AA65101E90");
+        assert!(TABLE.get(&inspectable) == Some(1));
+
+        let agile = Guid::from("94EA2B94-E9CC-49E0-C0FF-EE64CA8F5B90");
+        assert!(TABLE.get(&agile) == Some(2));
+
+        let absent = Guid::from("CFF52E04-CCA6-4614-A17E-754910C84A99");
+        assert!(TABLE.get(&absent).is_none());
+    }
+}
@@ -49,11 +49,19 @@ mod abi_transferable;
 mod activation_factory;
 mod agile_object;
 mod array;
+mod cached_guid;
 mod com;
 mod error;
 mod factory;
 mod guid;
+mod guid_ref;
+mod guid_registry;
+#[cfg(feature = "serde")]
+pub mod guid_serde;
+mod guid_slice_ext;
 mod hstring;
+#[cfg(feature = "phf")]
+mod iid_dispatch;
 mod object;
 mod param;
 mod runtime;
@@ -69,16 +77,30 @@ pub use activation_factory::IActivationFactory;
 pub use agile_object::IAgileObject;
 pub use array::Array;
 #[doc(hidden)]
+pub use cached_guid::CachedGuid;
+#[doc(hidden)]
 pub use com::*;
 pub use error::*;
 #[doc(hidden)]
 pub use factory::{factory, FactoryCache};
 #[doc(hidden)]
-pub use guid::Guid;
+pub use guid::{BadPiid, Guid, GuidParseError, MalformedSignature, ReservedGuid, SignatureHasher};
+#[doc(hidden)]
+pub use guid_ref::GuidRef;
+#[doc(hidden)]
+pub use guid_registry::{DuplicateRegistration, GuidRegistry};
+#[doc(hidden)]
+pub use guid_slice_ext::GuidSliceExt;
 pub use hstring::HString;
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+pub use iid_dispatch::IidDispatch;
 pub use object::Object;
 #[doc(hidden)]
 pub use param::Param;
+#[cfg(feature = "phf")]
+#[doc(hidden)]
+pub use phf;
 #[doc(hidden)]
 pub use runtime::*;
 #[doc(hidden)]
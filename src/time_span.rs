@@ -1,9 +1,11 @@
 use crate::*;
+use std::convert::TryFrom;
 
 /// Represents a time interval as a signed 64-bit integer value.
 ///
 /// TimeSpan represents the WinRT [TimeSpan](https://docs.microsoft.com/en-us/uwp/api/Windows.Foundation.TimeSpan)
-/// struct and provides convertibility with `std::time::Duration`.
+/// struct and provides convertibility with `std::time::Duration`. Converting to a `Duration`
+/// is fallible since a `TimeSpan` may be negative.
 #[repr(C)]
 #[derive(Copy, Clone, Default, Debug, PartialEq)]
 pub struct TimeSpan {
@@ -36,9 +38,16 @@ impl From<std::time::Duration> for TimeSpan {
     }
 }
 
-impl From<TimeSpan> for std::time::Duration {
-    fn from(value: TimeSpan) -> Self {
-        std::time::Duration::from_nanos((value.duration * 100) as u64)
+impl TryFrom<TimeSpan> for std::time::Duration {
+    type Error = std::num::TryFromIntError;
+
+    /// Fails if the `TimeSpan` is negative as a `Duration` can't represent it.
+    fn try_from(value: TimeSpan) -> std::result::Result<Self, Self::Error> {
+        const TICKS_PER_SECOND: u64 = 10_000_000;
+        let ticks = u64::try_from(value.duration)?;
+
+        Ok(std::time::Duration::from_secs(ticks / TICKS_PER_SECOND)
+            + std::time::Duration::from_nanos((ticks % TICKS_PER_SECOND) * 100))
     }
 }
 
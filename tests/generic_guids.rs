@@ -216,5 +216,33 @@ fn generic_guids() -> winrt::Result<()> {
 
     // TODO: etc.
 
+    //
+    // Nested generic GUIDs
+    //
+
+    assert_eq!(
+        windows::foundation::collections::IVector::<windows::foundation::IReference<i32>>::iid(),
+        winrt::Guid::from("847BD694-79C7-5471-8391-050A10EA625F")
+    );
+
+    //
+    // Guid::of
+    //
+
+    assert_eq!(
+        winrt::Guid::of::<windows::foundation::IReference<i32>>(),
+        winrt::Guid::from("548CEFBD-BC8A-5FA0-8DF2-957440FC8BF4")
+    );
+
+    assert_eq!(
+        winrt::Guid::of::<windows::foundation::IStringable>(),
+        windows::foundation::IStringable::iid()
+    );
+
+    assert_eq!(
+        winrt::Guid::of::<windows::foundation::AsyncActionCompletedHandler>(),
+        windows::foundation::AsyncActionCompletedHandler::iid()
+    );
+
     Ok(())
 }
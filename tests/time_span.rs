@@ -7,10 +7,11 @@ winrt::import!(
         windows::foundation::*
 );
 
+use std::convert::TryFrom;
 use std::time::Duration;
 use test_component::TestRunner;
 use windows::foundation::*;
-use winrt::TryInto;
+use winrt::{ComInterface, TryInto};
 
 #[test]
 fn conversion() -> winrt::Result<()> {
@@ -18,12 +19,36 @@ fn conversion() -> winrt::Result<()> {
     let b = TestRunner::create_time_span(1234)?;
     assert_eq!(a, b);
 
-    let c: Duration = b.into();
+    let c = Duration::try_from(b).unwrap();
     assert_eq!(c.as_millis(), 1234);
 
     Ok(())
 }
 
+#[test]
+fn duration_round_trip() {
+    let a = Duration::new(12, 345_678_900);
+    let b: winrt::TimeSpan = a.into();
+    assert_eq!(b.duration, 123_456_789);
+    assert_eq!(Duration::try_from(b).unwrap(), a);
+
+    let max = winrt::TimeSpan {
+        duration: std::i64::MAX,
+    };
+    assert_eq!(winrt::TimeSpan::from(Duration::try_from(max).unwrap()), max);
+
+    let negative = winrt::TimeSpan { duration: -1 };
+    assert!(Duration::try_from(negative).is_err());
+}
+
+#[test]
+fn reference_iid() {
+    assert_eq!(
+        IReference::<winrt::TimeSpan>::iid(),
+        winrt::Guid::from("604D0C4C-91DE-5C2A-935F-362F13EAF800")
+    );
+}
+
 #[test]
 fn duration_param() -> winrt::Result<()> {
     let object = PropertyValue::create_time_span(Duration::from_millis(1234))?;